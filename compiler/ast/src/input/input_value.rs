@@ -23,6 +23,7 @@ use std::fmt;
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum InputValue {
     Address(String),
+    Array(Vec<InputValue>),
     Boolean(bool),
     Field(String),
     Group(GroupLiteral),
@@ -50,6 +51,21 @@ impl TryFrom<(Type, Expression)> for InputValue {
                     return Err(InputError::unexpected_type(x, &y, y.span()).into());
                 }
             },
+            (Type::Array(array_type), Expression::Array(array)) => {
+                if array.elements.len() != array_type.length() {
+                    return Err(InputError::unexpected_array_length(
+                        array_type.length(),
+                        array.elements.len(),
+                        array.span,
+                    )
+                    .into());
+                }
+                let mut elements = Vec::with_capacity(array.elements.len());
+                for element in array.elements {
+                    elements.push(InputValue::try_from((array_type.element_type().clone(), element))?);
+                }
+                Self::Array(elements)
+            }
             (type_, Expression::Unary(unary)) if unary.op == UnaryOperation::Negate => {
                 InputValue::try_from((type_, *unary.receiver))?
             }
@@ -62,10 +78,13 @@ impl fmt::Display for InputValue {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             InputValue::Address(ref address) => write!(f, "{address}"),
+            InputValue::Array(ref elements) => {
+                write!(f, "[{}]", elements.iter().map(|element| element.to_string()).collect::<Vec<_>>().join(", "))
+            }
             InputValue::Boolean(ref boolean) => write!(f, "{boolean}"),
             InputValue::Group(ref group) => write!(f, "{group}"),
             InputValue::Field(ref field) => write!(f, "{field}"),
-            InputValue::Integer(ref type_, ref number) => write!(f, "{number}{type_:?}"),
+            InputValue::Integer(ref type_, ref number) => write!(f, "{number}{type_}"),
         }
     }
 }
@@ -42,6 +42,26 @@ impl IntegerType {
         matches!(self, I8 | I16 | I32 | I64 | I128)
     }
 
+    /// Returns the number of bits in the integer type.
+    pub fn bit_width(&self) -> usize {
+        use IntegerType::*;
+        match self {
+            U8 | I8 => 8,
+            U16 | I16 => 16,
+            U32 | I32 => 32,
+            U64 | I64 => 64,
+            U128 | I128 => 128,
+        }
+    }
+
+    /// Returns the maximum value representable by the integer type.
+    pub fn max_value(&self) -> u128 {
+        match self.is_signed() {
+            true => u128::MAX >> (129 - self.bit_width()),
+            false => u128::MAX >> (128 - self.bit_width()),
+        }
+    }
+
     /// Returns the symbol for the integer type.
     pub fn symbol(self) -> Symbol {
         match self {
@@ -76,3 +96,30 @@ impl fmt::Display for IntegerType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bit_width_and_max_value() {
+        use IntegerType::*;
+        let expected = [
+            (U8, 8, u8::MAX as u128),
+            (U16, 16, u16::MAX as u128),
+            (U32, 32, u32::MAX as u128),
+            (U64, 64, u64::MAX as u128),
+            (U128, 128, u128::MAX),
+            (I8, 8, i8::MAX as u128),
+            (I16, 16, i16::MAX as u128),
+            (I32, 32, i32::MAX as u128),
+            (I64, 64, i64::MAX as u128),
+            (I128, 128, i128::MAX as u128),
+        ];
+
+        for (type_, bit_width, max_value) in expected {
+            assert_eq!(type_.bit_width(), bit_width, "bit width of {type_}");
+            assert_eq!(type_.max_value(), max_value, "max value of {type_}");
+        }
+    }
+}
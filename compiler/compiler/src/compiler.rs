@@ -194,6 +194,7 @@ impl<'a> Compiler<'a> {
             &self.node_builder,
             symbol_table,
             &self.type_table,
+            self.compiler_options.build.loop_unrolling_limit,
         ))?;
         self.ast = ast;
 
@@ -28,6 +28,8 @@ pub struct CompilerOptions {
 pub struct BuildOptions {
     /// Whether to enable dead code elimination.
     pub dce_enabled: bool,
+    /// The maximum number of iterations a single loop may be unrolled into. Defaults to `None`, which places no limit on loops.
+    pub loop_unrolling_limit: Option<usize>,
 }
 
 #[derive(Clone, Default)]
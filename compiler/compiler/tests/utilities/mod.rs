@@ -76,6 +76,12 @@ pub fn get_cwd_option(test: &Test) -> Option<PathBuf> {
 }
 
 pub fn get_build_options(test_config: &TestConfig) -> Vec<BuildOptions> {
+    // The loop unrolling limit, if any, applies to every compiler configuration.
+    let loop_unrolling_limit = test_config
+        .extra
+        .get("loop_unrolling_limit")
+        .map(|val| val.as_u64().expect("Expected `loop_unrolling_limit` to be an unsigned integer.") as usize);
+
     match test_config.extra.get("configs") {
         Some(configs) => {
             // Parse the sequence of compiler configurations.
@@ -96,11 +102,12 @@ pub fn get_build_options(test_config: &TestConfig) -> Vec<BuildOptions> {
                             .expect("Expected key `dce_enabled`")
                             .as_bool()
                             .expect("Expected value to be a boolean."),
+                        loop_unrolling_limit,
                     }
                 })
                 .collect()
         }
-        None => vec![BuildOptions { dce_enabled: true }],
+        None => vec![BuildOptions { dce_enabled: true, loop_unrolling_limit }],
     }
 }
 
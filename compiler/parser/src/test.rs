@@ -16,7 +16,8 @@
 
 use crate::{tokenizer, ParserContext, SpannedToken};
 
-use leo_ast::{NodeBuilder, NodeID, Statement};
+use indexmap::IndexMap;
+use leo_ast::{NodeBuilder, NodeID, ProgramInput, Statement};
 use leo_errors::{emitter::Handler, LeoError};
 use leo_span::{
     source_map::FileName,
@@ -214,6 +215,29 @@ impl Namespace for InputNamespace {
     }
 }
 
+struct ProgramInputNamespace;
+
+impl Namespace for ProgramInputNamespace {
+    fn parse_type(&self) -> ParseType {
+        ParseType::Whole
+    }
+
+    fn run_test(&self, test: Test) -> Result<Value, String> {
+        create_session_if_not_set_then(|s| {
+            let input = with_handler(tokenize(test, s)?, |p| p.parse_input_file())?;
+            let program_input = ProgramInput::try_from(input).map_err(|err| err.to_string())?;
+            // Display each processed value, so both the conversion and its formatting are checked.
+            let values = program_input
+                .main
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect::<IndexMap<_, _>>();
+
+            Ok(yaml_or_fail(values))
+        })
+    }
+}
+
 struct TestRunner;
 
 impl Runner for TestRunner {
@@ -224,6 +248,7 @@ impl Runner for TestRunner {
             "ParseStatement" => Box::new(ParseStatementNamespace),
             "Serialize" => Box::new(SerializeNamespace),
             "Input" => Box::new(InputNamespace),
+            "ProgramInput" => Box::new(ProgramInputNamespace),
             "Token" => Box::new(TokenNamespace),
             _ => return None,
         })
@@ -35,11 +35,11 @@ use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
 use leo_errors::{emitter::Handler, Result};
 
 impl<'a> Pass for Unroller<'a> {
-    type Input = (Ast, &'a Handler, &'a NodeBuilder, SymbolTable, &'a TypeTable);
+    type Input = (Ast, &'a Handler, &'a NodeBuilder, SymbolTable, &'a TypeTable, Option<usize>);
     type Output = Result<(Ast, SymbolTable)>;
 
-    fn do_pass((ast, handler, node_builder, st, tt): Self::Input) -> Self::Output {
-        let mut reconstructor = Self::new(st, tt, handler, node_builder);
+    fn do_pass((ast, handler, node_builder, st, tt, loop_unrolling_limit): Self::Input) -> Self::Output {
+        let mut reconstructor = Self::new(st, tt, handler, node_builder, loop_unrolling_limit);
         let program = reconstructor.reconstruct_program(ast.into_repr());
         handler.last_err().map_err(|e| *e)?;
 
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use indexmap::IndexSet;
 use leo_ast::{
     Block,
     Expression,
@@ -21,6 +22,7 @@ use leo_ast::{
     IterationStatement,
     Literal,
     NodeBuilder,
+    NodeID,
     Statement,
     StatementReconstructor,
     Type,
@@ -54,6 +56,10 @@ pub struct Unroller<'a> {
     pub(crate) node_builder: &'a NodeBuilder,
     /// Are we in the midst of unrolling a loop?
     pub(crate) is_unrolling: bool,
+    /// The maximum number of iterations a single loop may be unrolled into.
+    pub(crate) loop_unrolling_limit: Option<usize>,
+    /// The IDs of the loops that have already been reported as exceeding the unrolling limit.
+    pub(crate) loops_exceeding_limit: IndexSet<NodeID>,
 }
 
 impl<'a> Unroller<'a> {
@@ -62,6 +68,7 @@ impl<'a> Unroller<'a> {
         type_table: &'a TypeTable,
         handler: &'a Handler,
         node_builder: &'a NodeBuilder,
+        loop_unrolling_limit: Option<usize>,
     ) -> Self {
         Self {
             constant_propagation_table: RefCell::new(ConstantPropagationTable::default()),
@@ -71,6 +78,8 @@ impl<'a> Unroller<'a> {
             handler,
             node_builder,
             is_unrolling: false,
+            loop_unrolling_limit,
+            loops_exceeding_limit: IndexSet::new(),
         }
     }
 
@@ -142,6 +151,19 @@ impl<'a> Unroller<'a> {
             Err(s) => return s,
         };
 
+        // Check that the loop does not exceed the unrolling limit.
+        if let Some(limit) = self.loop_unrolling_limit {
+            let clusivity = if input.inclusive { Clusivity::Inclusive } else { Clusivity::Exclusive };
+            // Only count up to one past the limit, since the range may be very large.
+            if RangeIterator::new(start, stop, clusivity).take(limit.saturating_add(1)).count() > limit {
+                // A nested loop is unrolled once per iteration of its enclosing loops, so only report it the first time.
+                if self.loops_exceeding_limit.insert(input.id) {
+                    self.emit_err(LoopUnrollerError::loop_unrolling_limit_exceeded(limit, input.span));
+                }
+                return Statement::dummy(input.span, self.node_builder.next_id());
+            }
+        }
+
         // Get the index of the current scope.
         let scope_index = self.current_scope_index();
 
@@ -55,4 +55,14 @@ create_messages!(
         ),
         help: None,
     }
+
+    /// For when the number of elements in an array input does not match its declared length.
+    @formatted
+    unexpected_array_length {
+        args: (expected: impl Display, received: impl Display),
+        msg: format!(
+            "unexpected array length, expected: '{expected}', received: '{received}'",
+        ),
+        help: None,
+    }
 );
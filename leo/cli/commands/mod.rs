@@ -133,6 +133,8 @@ pub struct BuildOptions {
     pub enable_ast_spans: bool,
     #[clap(long, help = "Enable spans in symbol table snapshots.")]
     pub enable_dce: bool,
+    #[clap(long, help = "Limits the iterations a single loop may unroll into. Loops are unlimited by default.")]
+    pub loop_unrolling_limit: Option<usize>,
     #[clap(long, help = "Writes all AST snapshots for the different compiler phases.")]
     pub enable_all_ast_snapshots: bool,
     #[clap(long, help = "Writes Input AST snapshot of the initial parse.")]